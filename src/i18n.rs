@@ -0,0 +1,141 @@
+use std::env;
+
+/// Minimal keyed-catalog i18n layer: no Fluent/ICU dependency, just a flat
+/// key -> template lookup per locale with English as the fallback for any
+/// key a locale doesn't (yet) translate. Templates may contain a single
+/// `{detail}` placeholder that callers substitute at render time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    Es,
+}
+
+impl Locale {
+    pub const ALL: &'static [Locale] = &[Locale::En, Locale::Es];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+        }
+    }
+
+    fn table(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::En => EN,
+            Locale::Es => ES,
+        }
+    }
+
+    /// Picks a locale from `LC_MESSAGES`/`LANG` (in that priority order),
+    /// falling back to English when neither is set or recognized.
+    pub fn detect() -> Locale {
+        let env_locale = env::var("LC_MESSAGES")
+            .or_else(|_| env::var("LANG"))
+            .unwrap_or_default();
+
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|locale| env_locale.starts_with(locale.code()))
+            .unwrap_or(Locale::En)
+    }
+}
+
+pub struct Catalog {
+    locale: Locale,
+}
+
+impl Catalog {
+    pub fn new(locale: Locale) -> Self {
+        Self { locale }
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    /// Looks up `key` in the active locale, falling back to English and
+    /// finally to the key itself so a missing translation never panics.
+    pub fn get(&self, key: &str) -> String {
+        lookup(self.locale.table(), key)
+            .or_else(|| lookup(EN, key))
+            .unwrap_or_else(|| key.to_string())
+    }
+}
+
+fn lookup(table: &[(&str, &str)], key: &str) -> Option<String> {
+    table
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v.to_string())
+}
+
+const EN: &[(&str, &str)] = &[
+    ("app.title", "Ubuntu Package Manager"),
+    ("menu.apt", "Apt Packages"),
+    ("menu.flatpak", "Flatpack Packages"),
+    ("menu.snap", "Snap Packages"),
+    ("menu.upgradable", "Upgradable"),
+    ("menu.language", "Language"),
+    ("search.placeholder", "Name"),
+    ("search.installed_only", "Searching: Installed"),
+    ("search.installed_and_remote", "Searching: Installed + Remote"),
+    ("column.source", "Source"),
+    ("column.name", "Name"),
+    ("column.version", "Version"),
+    ("column.update", "Update"),
+    ("package.up_to_date", "Up to date"),
+    ("action.install", "Install"),
+    ("action.remove", "Remove"),
+    ("action.upgrade", "Upgrade"),
+    ("action.dismiss", "Dismiss"),
+    ("severity.info", "Info"),
+    ("severity.warning", "Warning"),
+    ("severity.error", "Error"),
+    ("error.apt", "APT error: {detail}"),
+    ("error.flatpak", "Flatpak error: {detail}"),
+    ("error.snap", "Snap error: {detail}"),
+    ("operation.completed", "operation completed"),
+    ("operation.failed", "operation failed: {detail}"),
+];
+
+const ES: &[(&str, &str)] = &[
+    ("app.title", "Gestor de Paquetes de Ubuntu"),
+    ("menu.apt", "Paquetes Apt"),
+    ("menu.flatpak", "Paquetes Flatpack"),
+    ("menu.snap", "Paquetes Snap"),
+    ("menu.upgradable", "Actualizables"),
+    ("menu.language", "Idioma"),
+    ("search.placeholder", "Nombre"),
+    ("search.installed_only", "Buscando: Instalados"),
+    ("search.installed_and_remote", "Buscando: Instalados + Remotos"),
+    ("column.source", "Origen"),
+    ("column.name", "Nombre"),
+    ("column.version", "Versión"),
+    ("column.update", "Actualización"),
+    ("package.up_to_date", "Actualizado"),
+    ("action.install", "Instalar"),
+    ("action.remove", "Eliminar"),
+    ("action.upgrade", "Actualizar"),
+    ("action.dismiss", "Descartar"),
+    ("severity.info", "Info"),
+    ("severity.warning", "Aviso"),
+    ("severity.error", "Error"),
+    ("error.apt", "Error de APT: {detail}"),
+    ("error.flatpak", "Error de Flatpak: {detail}"),
+    ("error.snap", "Error de Snap: {detail}"),
+    ("operation.completed", "operación completada"),
+    ("operation.failed", "operación fallida: {detail}"),
+];
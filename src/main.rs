@@ -1,16 +1,19 @@
+mod i18n;
+
+use i18n::{Catalog, Locale};
 use iced::{
     Element, Length, Task, Theme,
-    widget::{Container, Scrollable, button, column, container, row, scrollable, text, text_input},
+    widget::{Container, button, column, container, row, scrollable, text, text_input},
 };
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::process::Command;
 
 pub fn main() -> iced::Result {
-    iced::application(AppState::new, AppState::update, AppState::view)
-        .theme(Theme::Dark)
-        .title("Ubuntu Package Manager")
-        .run()
+    iced::application(AppState::title, AppState::update, AppState::view)
+        .theme(|_state| Theme::Dark)
+        .run_with(AppState::new)
 }
 
 struct AppState {
@@ -19,6 +22,27 @@ struct AppState {
     snap_packages: Vec<Package>,
     current_page: Page,
     text_search: String,
+    statuses: Vec<StatusMessage>,
+    remote_results: Vec<Package>,
+    remote_search_forced: bool,
+    search_generation: u64,
+    catalog: Catalog,
+}
+
+#[derive(Debug, Clone)]
+struct StatusMessage {
+    severity: Severity,
+    source: Option<Source>,
+    key: &'static str,
+    detail: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Info,
+    #[allow(dead_code)]
+    Warning,
+    Error,
 }
 
 #[derive(Debug, Clone)]
@@ -26,9 +50,11 @@ struct Package {
     source: Source,
     name: String,
     version: String,
+    available: Option<String>,
+    installed: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Source {
     Flatpak,
     Apt,
@@ -47,16 +73,18 @@ impl Display for Source {
 
 #[derive(Debug, Clone)]
 enum Message {
-    AppsLoaded(Result<PackageLists, String>),
+    BackendLoaded(Source, Result<Vec<Package>, String>),
     Navigate(Page),
     TextSearchChange(String),
-}
-
-#[derive(Debug, Clone)]
-struct PackageLists {
-    flatpak_packages: Vec<Package>,
-    apt_packages: Vec<Package>,
-    snap_packages: Vec<Package>,
+    InstallPackage(Package),
+    RemovePackage(Package),
+    UpgradePackage(Package),
+    OperationCompleted(Source, Result<(), String>),
+    DismissStatus(Option<Source>),
+    RemoteSearchTick(u64, String),
+    RemoteSearchResults(Source, Result<Vec<Package>, String>),
+    ToggleRemoteSearch(bool),
+    SetLocale(Locale),
 }
 
 #[derive(Debug, Clone)]
@@ -64,6 +92,7 @@ enum Page {
     Apt,
     Flatpak,
     Snap,
+    Upgradable,
 }
 
 impl AppState {
@@ -74,76 +103,352 @@ impl AppState {
             snap_packages: Vec::new(),
             current_page: Page::Apt,
             text_search: String::new(),
+            statuses: Vec::new(),
+            remote_results: Vec::new(),
+            remote_search_forced: false,
+            search_generation: 0,
+            catalog: Catalog::new(Locale::detect()),
         };
 
-        let task = Task::perform(load_app_lists(), Message::AppsLoaded);
+        let task = reload_all_backends();
 
         (state, task)
     }
 
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::AppsLoaded(result) => match result {
-                Ok(lists) => {
-                    self.flatpak_packages = lists.flatpak_packages;
-                    self.apt_packages = lists.apt_packages;
-                    self.snap_packages = lists.snap_packages;
+            Message::BackendLoaded(source, result) => match result {
+                Ok(packages) => {
+                    match source {
+                        Source::Apt => self.apt_packages = packages,
+                        Source::Flatpak => self.flatpak_packages = packages,
+                        Source::Snap => self.snap_packages = packages,
+                    }
+                    self.clear_backend_error(source);
                 }
                 Err(e) => {
-                    eprintln!("Error loading apps: {}", e);
+                    self.push_status(StatusMessage {
+                        severity: Severity::Error,
+                        source: Some(source),
+                        key: backend_error_key(source),
+                        detail: e,
+                    });
                 }
             },
             Message::Navigate(page) => {
                 self.current_page = page;
                 self.text_search = String::new();
+                self.remote_results.clear();
+                self.search_generation += 1;
+            }
+            Message::TextSearchChange(term) => {
+                self.text_search = term.clone();
+                self.remote_results.clear();
+                self.search_generation += 1;
+
+                return Task::perform(
+                    debounce_search(self.search_generation, term),
+                    |(generation, query)| Message::RemoteSearchTick(generation, query),
+                );
+            }
+            Message::InstallPackage(pkg) => {
+                return Task::perform(perform_package_action(pkg, PackageAction::Install), |(source, result)| {
+                    Message::OperationCompleted(source, result)
+                });
+            }
+            Message::RemovePackage(pkg) => {
+                return Task::perform(perform_package_action(pkg, PackageAction::Remove), |(source, result)| {
+                    Message::OperationCompleted(source, result)
+                });
+            }
+            Message::UpgradePackage(pkg) => {
+                return Task::perform(perform_package_action(pkg, PackageAction::Upgrade), |(source, result)| {
+                    Message::OperationCompleted(source, result)
+                });
+            }
+            Message::OperationCompleted(source, result) => {
+                self.push_status(match result {
+                    Ok(()) => StatusMessage {
+                        severity: Severity::Info,
+                        source: Some(source),
+                        key: "operation.completed",
+                        detail: String::new(),
+                    },
+                    Err(e) => StatusMessage {
+                        severity: Severity::Error,
+                        source: Some(source),
+                        key: "operation.failed",
+                        detail: e,
+                    },
+                });
+                return Task::perform(load_backend(source), |(source, result)| {
+                    Message::BackendLoaded(source, result)
+                });
+            }
+            Message::DismissStatus(source) => self.statuses.retain(|status| status.source != source),
+            Message::RemoteSearchTick(generation, query) => {
+                if generation != self.search_generation || query.is_empty() {
+                    return Task::none();
+                }
+
+                if matches!(self.current_page, Page::Upgradable) {
+                    return Task::none();
+                }
+
+                if self.has_installed_match(&query) && !self.remote_search_forced {
+                    return Task::none();
+                }
+
+                return Task::batch([
+                    Task::perform(remote_search(Source::Apt, query.clone()), |(source, result)| {
+                        Message::RemoteSearchResults(source, result)
+                    }),
+                    Task::perform(remote_search(Source::Flatpak, query.clone()), |(source, result)| {
+                        Message::RemoteSearchResults(source, result)
+                    }),
+                    Task::perform(remote_search(Source::Snap, query), |(source, result)| {
+                        Message::RemoteSearchResults(source, result)
+                    }),
+                ]);
+            }
+            Message::RemoteSearchResults(source, result) => {
+                self.remote_results.retain(|pkg| pkg.source != source);
+                match result {
+                    Ok(packages) => {
+                        self.remote_results.extend(packages);
+                        self.clear_backend_error(source);
+                    }
+                    Err(e) => {
+                        self.push_status(StatusMessage {
+                            severity: Severity::Error,
+                            source: Some(source),
+                            key: backend_error_key(source),
+                            detail: e,
+                        });
+                    }
+                }
             }
-            Message::TextSearchChange(term) => self.text_search = term,
+            Message::ToggleRemoteSearch(forced) => {
+                self.remote_search_forced = forced;
+                self.remote_results.clear();
+                self.search_generation += 1;
+
+                return Task::perform(
+                    debounce_search(self.search_generation, self.text_search.clone()),
+                    |(generation, query)| Message::RemoteSearchTick(generation, query),
+                );
+            }
+            Message::SetLocale(locale) => self.catalog.set_locale(locale),
         }
         Task::none()
     }
+
+    fn title(&self) -> String {
+        self.catalog.get("app.title")
+    }
+
+    /// Upserts a status by source so concurrent failures from different
+    /// backends (e.g. flatpak and snap both missing) are all shown instead
+    /// of the latest one silently replacing the others.
+    fn push_status(&mut self, status: StatusMessage) {
+        self.statuses.retain(|existing| existing.source != status.source);
+        self.statuses.push(status);
+    }
+
+    /// Clears a stale backend-load error for `source` on a successful reload,
+    /// without touching other status kinds (e.g. an operation-result banner
+    /// from a reload that an install/remove/upgrade just triggered).
+    fn clear_backend_error(&mut self, source: Source) {
+        let key = backend_error_key(source);
+        self.statuses
+            .retain(|status| !(status.source == Some(source) && status.key == key));
+    }
+
+    fn has_installed_match(&self, query: &str) -> bool {
+        let needle = query.to_lowercase();
+        let installed = match self.current_page {
+            Page::Apt => &self.apt_packages,
+            Page::Flatpak => &self.flatpak_packages,
+            Page::Snap => &self.snap_packages,
+            Page::Upgradable => return false,
+        };
+
+        installed
+            .iter()
+            .any(|pkg| pkg.name.to_lowercase().contains(&needle))
+    }
 }
 
-async fn load_app_lists() -> Result<PackageLists, String> {
-    let mut errors = Vec::new();
-    let mut flatpak_apps = Vec::new();
-    let mut apt_apps = Vec::new();
-    let mut snap_apps = Vec::new();
+#[derive(Debug, Clone, Copy)]
+enum PackageAction {
+    Install,
+    Remove,
+    Upgrade,
+}
 
-    match load_apt() {
-        Ok(apps) => {
-            apt_apps = apps;
-        }
-        Err(error) => {
-            errors.push(format!("APT error: {}", error));
-        }
+async fn perform_package_action(pkg: Package, action: PackageAction) -> (Source, Result<(), String>) {
+    let source = pkg.source;
+    let result = tokio::task::spawn_blocking(move || match action {
+        PackageAction::Install => install_package(&pkg),
+        PackageAction::Remove => remove_package(&pkg),
+        PackageAction::Upgrade => upgrade_package(&pkg),
+    })
+    .await
+    .unwrap_or_else(|e| Err(format!("background task failed: {e}")));
+
+    (source, result.map(|_| ()))
+}
+
+fn run_privileged_cmd(args: &[&str]) -> Result<String, String> {
+    run_cmd("pkexec", args)
+}
+
+fn install_package(pkg: &Package) -> Result<String, String> {
+    match pkg.source {
+        Source::Apt => run_privileged_cmd(&["apt-get", "install", "-y", &pkg.name]),
+        Source::Flatpak => run_privileged_cmd(&["flatpak", "install", "-y", &pkg.name]),
+        Source::Snap => run_privileged_cmd(&["snap", "install", &pkg.name]),
     }
+}
 
-    match load_flatpak() {
-        Ok(apps) => {
-            flatpak_apps = apps;
-        }
-        Err(error) => {
-            errors.push(format!("Flatpak error: {}", error));
+fn remove_package(pkg: &Package) -> Result<String, String> {
+    match pkg.source {
+        Source::Apt => run_privileged_cmd(&["apt-get", "remove", "-y", &pkg.name]),
+        Source::Flatpak => run_privileged_cmd(&["flatpak", "uninstall", "-y", &pkg.name]),
+        Source::Snap => run_privileged_cmd(&["snap", "remove", &pkg.name]),
+    }
+}
+
+fn upgrade_package(pkg: &Package) -> Result<String, String> {
+    match pkg.source {
+        Source::Apt => run_privileged_cmd(&["apt-get", "install", "--only-upgrade", "-y", &pkg.name]),
+        Source::Flatpak => run_privileged_cmd(&["flatpak", "update", "-y", &pkg.name]),
+        Source::Snap => run_privileged_cmd(&["snap", "refresh", &pkg.name]),
+    }
+}
+
+async fn debounce_search(generation: u64, query: String) -> (u64, String) {
+    tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+    (generation, query)
+}
+
+async fn remote_search(source: Source, query: String) -> (Source, Result<Vec<Package>, String>) {
+    let result = tokio::task::spawn_blocking(move || match source {
+        Source::Apt => search_apt_remote(&query),
+        Source::Flatpak => search_flatpak_remote(&query),
+        Source::Snap => search_snap_remote(&query),
+    })
+    .await
+    .unwrap_or_else(|e| Err(format!("background task failed: {e}")));
+
+    (source, result)
+}
+
+fn search_apt_remote(query: &str) -> Result<Vec<Package>, String> {
+    let stdout = run_cmd("apt-cache", &["search", query])?;
+
+    let mut pkgs = Vec::new();
+
+    for line in stdout.lines() {
+        let Some((name, _description)) = line.split_once(" - ") else {
+            continue;
+        };
+
+        pkgs.push(Package {
+            source: Source::Apt,
+            name: name.trim().to_string(),
+            version: String::new(),
+            available: None,
+            installed: false,
+        });
+    }
+
+    Ok(pkgs)
+}
+
+fn search_flatpak_remote(query: &str) -> Result<Vec<Package>, String> {
+    let stdout = run_cmd("flatpak", &["search", query])?;
+
+    let mut pkgs = Vec::new();
+
+    for line in stdout.lines().skip(1) {
+        let cols: Vec<&str> = line.split('\t').collect();
+        let name = cols.get(2).unwrap_or(&"").trim();
+
+        if name.is_empty() {
+            continue;
         }
+
+        pkgs.push(Package {
+            source: Source::Flatpak,
+            name: name.to_string(),
+            version: String::new(),
+            available: None,
+            installed: false,
+        });
     }
 
-    match load_snap() {
-        Ok(apps) => {
-            snap_apps = apps;
+    Ok(pkgs)
+}
+
+fn search_snap_remote(query: &str) -> Result<Vec<Package>, String> {
+    let stdout = run_cmd("snap", &["find", query])?;
+
+    let mut pkgs = Vec::new();
+
+    for (i, line) in stdout.lines().enumerate() {
+        if i == 0 {
+            continue;
         }
-        Err(error) => {
-            errors.push(format!("Snap error: {}", error));
+
+        let cols: Vec<&str> = line.split_whitespace().collect();
+        if cols.len() < 2 {
+            continue;
         }
+
+        pkgs.push(Package {
+            source: Source::Snap,
+            name: cols[0].to_string(),
+            version: cols[1].to_string(),
+            available: None,
+            installed: false,
+        });
     }
 
-    if errors.is_empty() {
-        Ok(PackageLists {
-            flatpak_packages: flatpak_apps,
-            apt_packages: apt_apps,
-            snap_packages: snap_apps,
-        })
-    } else {
-        Err(errors.join("\n"))
+    Ok(pkgs)
+}
+
+fn reload_all_backends() -> Task<Message> {
+    Task::batch([
+        Task::perform(load_backend(Source::Apt), |(source, result)| {
+            Message::BackendLoaded(source, result)
+        }),
+        Task::perform(load_backend(Source::Flatpak), |(source, result)| {
+            Message::BackendLoaded(source, result)
+        }),
+        Task::perform(load_backend(Source::Snap), |(source, result)| {
+            Message::BackendLoaded(source, result)
+        }),
+    ])
+}
+
+async fn load_backend(source: Source) -> (Source, Result<Vec<Package>, String>) {
+    let result = tokio::task::spawn_blocking(move || match source {
+        Source::Apt => load_apt(),
+        Source::Flatpak => load_flatpak(),
+        Source::Snap => load_snap(),
+    })
+    .await
+    .unwrap_or_else(|e| Err(format!("background task failed: {e}")));
+
+    (source, result)
+}
+
+fn backend_error_key(source: Source) -> &'static str {
+    match source {
+        Source::Apt => "error.apt",
+        Source::Flatpak => "error.flatpak",
+        Source::Snap => "error.snap",
     }
 }
 
@@ -180,8 +485,9 @@ fn load_manual_set() -> Result<HashSet<String>, String> {
         .collect())
 }
 
-pub fn load_apt() -> Result<Vec<Package>, String> {
+fn load_apt() -> Result<Vec<Package>, String> {
     let manual = load_manual_set()?;
+    let upgradable = load_apt_upgradable().unwrap_or_default();
 
     let stdout = run_cmd("dpkg-query", &["-W", "-f=${Package}\t${Version}\n"])?;
 
@@ -209,6 +515,8 @@ pub fn load_apt() -> Result<Vec<Package>, String> {
                 source: Source::Apt,
                 name: name.to_string(),
                 version: version.to_string(),
+                available: upgradable.get(name).cloned(),
+                installed: true,
             });
         }
     }
@@ -216,7 +524,30 @@ pub fn load_apt() -> Result<Vec<Package>, String> {
     Ok(pkgs)
 }
 
-pub fn load_flatpak() -> Result<Vec<Package>, String> {
+fn load_apt_upgradable() -> Result<HashMap<String, String>, String> {
+    let stdout = run_cmd("apt", &["list", "--upgradable"])?;
+
+    let mut available = HashMap::new();
+
+    for line in stdout.lines() {
+        let Some((name, rest)) = line.split_once('/') else {
+            continue;
+        };
+
+        let version = rest.split_whitespace().nth(1).unwrap_or("");
+        if version.is_empty() {
+            continue;
+        }
+
+        available.insert(name.trim().to_string(), version.to_string());
+    }
+
+    Ok(available)
+}
+
+fn load_flatpak() -> Result<Vec<Package>, String> {
+    let upgradable = load_flatpak_upgradable().unwrap_or_default();
+
     let stdout = run_cmd(
         "flatpak",
         &[
@@ -234,7 +565,7 @@ pub fn load_flatpak() -> Result<Vec<Package>, String> {
             continue;
         }
 
-        let name = cols.get(0).unwrap_or(&"").trim();
+        let name = cols.first().unwrap_or(&"").trim();
         let version = cols.get(1).unwrap_or(&"").trim();
 
         if name.is_empty() {
@@ -245,13 +576,37 @@ pub fn load_flatpak() -> Result<Vec<Package>, String> {
             source: Source::Flatpak,
             name: name.to_string(),
             version: version.to_string(),
+            available: upgradable.get(name).cloned(),
+            installed: true,
         });
     }
 
     Ok(pkgs)
 }
 
-pub fn load_snap() -> Result<Vec<Package>, String> {
+fn load_flatpak_upgradable() -> Result<HashMap<String, String>, String> {
+    let stdout = run_cmd(
+        "flatpak",
+        &["remote-ls", "--updates", "--columns=application,version"],
+    )?;
+
+    let mut available = HashMap::new();
+
+    for line in stdout.lines() {
+        let cols: Vec<&str> = line.split_whitespace().collect();
+        if cols.len() < 2 {
+            continue;
+        }
+
+        available.insert(cols[0].to_string(), cols[1].to_string());
+    }
+
+    Ok(available)
+}
+
+fn load_snap() -> Result<Vec<Package>, String> {
+    let upgradable = load_snap_upgradable().unwrap_or_default();
+
     let stdout = run_cmd("snap", &["list"])?;
 
     let mut pkgs = Vec::new();
@@ -278,12 +633,35 @@ pub fn load_snap() -> Result<Vec<Package>, String> {
             source: Source::Snap,
             name: name.to_string(),
             version: version.to_string(),
+            available: upgradable.get(name).cloned(),
+            installed: true,
         });
     }
 
     Ok(pkgs)
 }
 
+fn load_snap_upgradable() -> Result<HashMap<String, String>, String> {
+    let stdout = run_cmd("snap", &["refresh", "--list"])?;
+
+    let mut available = HashMap::new();
+
+    for (i, line) in stdout.lines().enumerate() {
+        if i == 0 {
+            continue;
+        }
+
+        let cols: Vec<&str> = line.split_whitespace().collect();
+        if cols.len() < 2 {
+            continue;
+        }
+
+        available.insert(cols[0].to_string(), cols[1].to_string());
+    }
+
+    Ok(available)
+}
+
 fn is_snap_runtime(name: &str, notes: &str) -> bool {
     if notes.contains("base") || notes.contains("kernel") || notes.contains("gadget") {
         return true;
@@ -295,35 +673,165 @@ fn is_snap_runtime(name: &str, notes: &str) -> bool {
         || name.starts_with("mesa-")
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VersionSegment {
+    Numeric(u64),
+    Alpha(String),
+}
+
+fn split_version(version: &str) -> Vec<VersionSegment> {
+    version
+        .split(['.', '-', ':'])
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match segment.parse::<u64>() {
+            Ok(n) => VersionSegment::Numeric(n),
+            Err(_) => VersionSegment::Alpha(segment.to_string()),
+        })
+        .collect()
+}
+
+fn compare_version_segments(a: &VersionSegment, b: &VersionSegment) -> Ordering {
+    match (a, b) {
+        (VersionSegment::Numeric(a), VersionSegment::Numeric(b)) => a.cmp(b),
+        (VersionSegment::Numeric(_), VersionSegment::Alpha(_)) => Ordering::Greater,
+        (VersionSegment::Alpha(_), VersionSegment::Numeric(_)) => Ordering::Less,
+        (VersionSegment::Alpha(a), VersionSegment::Alpha(b)) => a.cmp(b),
+    }
+}
+
+/// Compares two Debian/semver-ish version strings segment by segment. A
+/// missing trailing segment ranks lower than any present segment, so
+/// "1.2" < "1.2.1".
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let a_segments = split_version(a);
+    let b_segments = split_version(b);
+
+    for i in 0..a_segments.len().max(b_segments.len()) {
+        let ordering = match (a_segments.get(i), b_segments.get(i)) {
+            (Some(a), Some(b)) => compare_version_segments(a, b),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+fn is_upgrade_available(pkg: &Package) -> bool {
+    pkg.available
+        .as_deref()
+        .is_some_and(|available| compare_versions(available, &pkg.version) == Ordering::Greater)
+}
+
 impl AppState {
     fn view(&self) -> Element<'_, Message> {
-        let text_search_input =
-            text_input("Name", &self.text_search).on_input(Message::TextSearchChange);
-        container(row![
-            get_menu(),
-            column![text_search_input, get_page(&self)]
-        ])
-        .into()
+        let catalog = &self.catalog;
+
+        let text_search_input = text_input(&catalog.get("search.placeholder"), &self.text_search)
+            .on_input(Message::TextSearchChange);
+
+        let search_toggle_label = if self.remote_search_forced {
+            catalog.get("search.installed_and_remote")
+        } else {
+            catalog.get("search.installed_only")
+        };
+        let search_toggle =
+            button(text(search_toggle_label)).on_press(Message::ToggleRemoteSearch(!self.remote_search_forced));
+
+        let mut content = column![row![text_search_input, search_toggle].spacing(10)];
+
+        for status in &self.statuses {
+            content = content.push(get_status_banner(catalog, status));
+        }
+
+        content = content.push(get_page(self));
+
+        container(row![get_menu(catalog), content]).into()
     }
 }
 
-fn get_menu() -> Container<'static, Message> {
-    let apt_btn = button("Apt Packages").on_press(Message::Navigate(Page::Apt));
-    let flatpack_btn = button("Flatpack Packages").on_press(Message::Navigate(Page::Flatpak));
-    let snap_btn = button("Snap Packages").on_press(Message::Navigate(Page::Snap));
+fn get_status_banner(catalog: &Catalog, status: &StatusMessage) -> Element<'static, Message> {
+    let severity_key = match status.severity {
+        Severity::Info => "severity.info",
+        Severity::Warning => "severity.warning",
+        Severity::Error => "severity.error",
+    };
+    let prefix = catalog.get(severity_key);
+    let body = catalog.get(status.key).replace("{detail}", &status.detail);
 
-    container(column![apt_btn, flatpack_btn, snap_btn].spacing(10)).into()
+    let label = match &status.source {
+        Some(source) => format!("{}: [{}] {}", prefix, source, body),
+        None => format!("{}: {}", prefix, body),
+    };
+
+    row![
+        text(label).width(Length::Fill),
+        button(text(catalog.get("action.dismiss"))).on_press(Message::DismissStatus(status.source)),
+    ]
+    .spacing(10)
+    .padding(5)
+    .into()
+}
+
+fn get_menu(catalog: &Catalog) -> Container<'static, Message> {
+    let apt_btn = button(text(catalog.get("menu.apt"))).on_press(Message::Navigate(Page::Apt));
+    let flatpack_btn = button(text(catalog.get("menu.flatpak"))).on_press(Message::Navigate(Page::Flatpak));
+    let snap_btn = button(text(catalog.get("menu.snap"))).on_press(Message::Navigate(Page::Snap));
+    let upgradable_btn = button(text(catalog.get("menu.upgradable"))).on_press(Message::Navigate(Page::Upgradable));
+
+    let language_buttons = Locale::ALL.iter().fold(
+        column![text(catalog.get("menu.language"))].spacing(5),
+        |col, locale| {
+            let btn = button(locale.label());
+            let btn = if *locale == catalog.locale() {
+                btn
+            } else {
+                btn.on_press(Message::SetLocale(*locale))
+            };
+            col.push(btn)
+        },
+    );
+
+    container(column![apt_btn, flatpack_btn, snap_btn, upgradable_btn, language_buttons].spacing(10))
 }
 
 fn get_page(app_state: &AppState) -> Element<'_, Message> {
-    let packages = match &app_state.current_page {
-        Page::Apt => &app_state.apt_packages,
-        Page::Flatpak => &app_state.flatpak_packages,
-        Page::Snap => &app_state.snap_packages,
+    let current_source = match &app_state.current_page {
+        Page::Apt => Some(Source::Apt),
+        Page::Flatpak => Some(Source::Flatpak),
+        Page::Snap => Some(Source::Snap),
+        Page::Upgradable => None,
+    };
+
+    let mut packages: Vec<&Package> = match &app_state.current_page {
+        Page::Apt => app_state.apt_packages.iter().collect(),
+        Page::Flatpak => app_state.flatpak_packages.iter().collect(),
+        Page::Snap => app_state.snap_packages.iter().collect(),
+        Page::Upgradable => app_state
+            .apt_packages
+            .iter()
+            .chain(app_state.flatpak_packages.iter())
+            .chain(app_state.snap_packages.iter())
+            .filter(|pkg| is_upgrade_available(pkg))
+            .collect(),
     };
 
+    if let Some(source) = current_source {
+        packages.extend(
+            app_state
+                .remote_results
+                .iter()
+                .filter(move |pkg| pkg.source == source),
+        );
+    }
+
     let filtered: Vec<&Package> = packages
-        .iter()
+        .into_iter()
         .filter(|pkg| {
             if app_state.text_search.is_empty() {
                 true
@@ -335,31 +843,113 @@ fn get_page(app_state: &AppState) -> Element<'_, Message> {
         })
         .collect();
 
-    get_package_scrollable(filtered)
+    get_package_scrollable(&app_state.catalog, filtered)
 }
 
-fn get_package_scrollable(package_list: Vec<&Package>) -> Element<'_, Message> {
+fn get_package_scrollable<'a>(catalog: &Catalog, package_list: Vec<&'a Package>) -> Element<'a, Message> {
     let header_row = row![
-        text("Source").width(Length::FillPortion(2)),
-        text("Name").width(Length::FillPortion(4)),
-        text("Version").width(Length::FillPortion(2))
+        text(catalog.get("column.source")).width(Length::FillPortion(2)),
+        text(catalog.get("column.name")).width(Length::FillPortion(4)),
+        text(catalog.get("column.version")).width(Length::FillPortion(2)),
+        text(catalog.get("column.update")).width(Length::FillPortion(2)),
     ];
     container(
-        scrollable(package_list.iter().enumerate().fold(
+        scrollable(package_list.iter().copied().enumerate().fold(
             column![header_row].spacing(2),
             |col, (_, app)| {
-                col.push(
-                    row![
-                        text(format!("{:?}", app.source)).width(Length::FillPortion(1)),
-                        text(&app.name).width(Length::FillPortion(2)),
-                        text(&app.version).width(Length::FillPortion(2)),
-                    ]
-                    .spacing(10)
-                    .padding(5),
-                )
+                let mut package_row = row![
+                    text(format!("{:?}", app.source)).width(Length::FillPortion(1)),
+                    text(&app.name).width(Length::FillPortion(2)),
+                    text(&app.version).width(Length::FillPortion(2)),
+                ]
+                .spacing(10)
+                .padding(5);
+
+                if app.installed && is_upgrade_available(app) {
+                    package_row = package_row.push(
+                        text(format!(
+                            "-> {}",
+                            app.available.as_deref().unwrap_or("")
+                        ))
+                        .width(Length::FillPortion(2)),
+                    );
+                    package_row = package_row.push(
+                        button(text(catalog.get("action.upgrade"))).on_press(Message::UpgradePackage(app.clone())),
+                    );
+                } else if app.installed {
+                    package_row =
+                        package_row.push(text(catalog.get("package.up_to_date")).width(Length::FillPortion(2)));
+                } else {
+                    package_row = package_row.push(text("").width(Length::FillPortion(2)));
+                }
+
+                if app.installed {
+                    package_row = package_row.push(
+                        button(text(catalog.get("action.remove"))).on_press(Message::RemovePackage(app.clone())),
+                    );
+                } else {
+                    package_row = package_row.push(
+                        button(text(catalog.get("action.install"))).on_press(Message::InstallPackage(app.clone())),
+                    );
+                }
+
+                col.push(package_row)
             },
         ))
         .height(Length::Fill),
     )
     .into()
 }
+
+#[cfg(test)]
+mod version_tests {
+    use super::*;
+
+    #[test]
+    fn numeric_segments_compare_by_value_not_length() {
+        assert_eq!(compare_versions("1.2.10", "1.2.9"), Ordering::Greater);
+        assert_eq!(compare_versions("1.2.9", "1.2.10"), Ordering::Less);
+    }
+
+    #[test]
+    fn equal_versions_compare_equal() {
+        assert_eq!(compare_versions("1.0.0", "1.0.0"), Ordering::Equal);
+    }
+
+    #[test]
+    fn missing_trailing_segment_ranks_lower() {
+        assert_eq!(compare_versions("1.2", "1.2.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.2.1", "1.2"), Ordering::Greater);
+    }
+
+    #[test]
+    fn epoch_like_prefix_dominates_the_comparison() {
+        assert_eq!(compare_versions("2:1.0-1", "1:9.9-1"), Ordering::Greater);
+    }
+
+    #[test]
+    fn alpha_suffix_ranks_as_an_extra_present_segment() {
+        // Known limitation: this segment-by-segment comparator treats a
+        // trailing alpha suffix as just another present segment, so
+        // "1.0-beta" outranks "1.0" even though it's a pre-release.
+        assert_eq!(compare_versions("1.0-beta", "1.0"), Ordering::Greater);
+    }
+
+    #[test]
+    fn is_upgrade_available_reflects_comparison() {
+        let mut pkg = Package {
+            source: Source::Apt,
+            name: "demo".to_string(),
+            version: "1.0".to_string(),
+            available: Some("1.1".to_string()),
+            installed: true,
+        };
+        assert!(is_upgrade_available(&pkg));
+
+        pkg.available = Some("1.0".to_string());
+        assert!(!is_upgrade_available(&pkg));
+
+        pkg.available = None;
+        assert!(!is_upgrade_available(&pkg));
+    }
+}